@@ -0,0 +1,89 @@
+// Sidecar ESI proxy supervision.
+// Spawns, restarts, and tears down the bundled local caching/rate-limiting proxy.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
+
+/// How long to wait before respawning after the sidecar dies, so a
+/// fast-crashing binary (bad build, missing dependency, port-bind failure)
+/// doesn't busy-loop respawn attempts.
+const RESTART_BACKOFF: Duration = Duration::from_secs(2);
+
+/// The local port the sidecar is bound to, once it reports ready, and the
+/// handle needed to kill it on app shutdown.
+#[derive(Default)]
+pub struct EsiProxyState {
+    port: Mutex<Option<u16>>,
+    child: Mutex<Option<CommandChild>>,
+}
+
+/// Spawns the `esi-proxy` sidecar and restarts it if it exits unexpectedly.
+/// The sidecar prints `READY <port>` on stdout once its local server is
+/// listening, which is how we learn the port to hand back to the frontend.
+pub fn spawn(app: &AppHandle) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let (mut rx, child) = match app_handle.shell().sidecar("esi-proxy") {
+                Ok(cmd) => match cmd.spawn() {
+                    Ok(pair) => pair,
+                    Err(err) => {
+                        eprintln!("failed to spawn esi-proxy sidecar: {err}");
+                        break;
+                    }
+                },
+                Err(err) => {
+                    eprintln!("esi-proxy sidecar not found: {err}");
+                    break;
+                }
+            };
+
+            *app_handle.state::<EsiProxyState>().child.lock().unwrap() = Some(child);
+
+            while let Some(event) = rx.recv().await {
+                match event {
+                    CommandEvent::Stdout(line) => {
+                        let line = String::from_utf8_lossy(&line);
+                        if let Some(port_str) = line.trim().strip_prefix("READY ") {
+                            if let Ok(port) = port_str.trim().parse::<u16>() {
+                                *app_handle.state::<EsiProxyState>().port.lock().unwrap() = Some(port);
+                            }
+                        }
+                    }
+                    CommandEvent::Stderr(line) => {
+                        eprintln!("esi-proxy: {}", String::from_utf8_lossy(&line));
+                    }
+                    CommandEvent::Terminated(_) => break,
+                    _ => {}
+                }
+            }
+
+            // The sidecar exited (crash or otherwise); clear the advertised
+            // port and child handle, then back off before restarting.
+            app_handle.state::<EsiProxyState>().child.lock().unwrap().take();
+            *app_handle.state::<EsiProxyState>().port.lock().unwrap() = None;
+            eprintln!("esi-proxy sidecar exited, restarting in {RESTART_BACKOFF:?}");
+            tokio::time::sleep(RESTART_BACKOFF).await;
+        }
+    });
+}
+
+/// Kills the supervised sidecar, if one is running. Call this on app
+/// shutdown (tray Quit, `RunEvent::Exit`) so it doesn't linger as an orphan
+/// process.
+pub fn kill(app: &AppHandle) {
+    if let Some(child) = app.state::<EsiProxyState>().child.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+}
+
+/// Returns the local port the ESI proxy is currently listening on, so the
+/// frontend can point its fetches there instead of directly at ESI.
+#[tauri::command]
+pub fn esi_proxy_port(app: AppHandle) -> Option<u16> {
+    *app.state::<EsiProxyState>().port.lock().unwrap()
+}
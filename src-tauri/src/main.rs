@@ -3,7 +3,15 @@
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod esi_proxy;
+mod mobile;
+mod notifications;
+mod oauth;
+mod tray;
+mod updater;
+
 use tauri::Manager;
+use tauri_plugin_deep_link::DeepLinkExt;
 
 fn main() {
     tauri::Builder::default()
@@ -11,23 +19,94 @@ fn main() {
         .plugin(tauri_plugin_http::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_updater::init())
+        .manage(oauth::PendingAuth::default())
+        .manage(notifications::ScheduledNotifications::default())
+        .manage(esi_proxy::EsiProxyState::default())
         .setup(|app| {
             // Set up single instance on desktop platforms
             #[cfg(not(any(target_os = "android", target_os = "ios")))]
             {
-                app.handle().plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+                app.handle().plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+                    // On Windows/Linux a second launch with the custom scheme
+                    // arrives as an argv entry rather than an open-url event.
+                    if let Some(url) = args.iter().find(|arg| arg.starts_with("eve-sentinel://")) {
+                        oauth::handle_callback_url(app, url);
+                    }
+
                     if let Some(window) = app.get_webview_window("main") {
                         let _ = window.set_focus();
                     }
                 }))?;
             }
 
+            // Handle the ESI OAuth deep-link callback when the app is
+            // launched fresh (or resumed) with an `eve-sentinel://` URL.
+            let app_handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    oauth::handle_callback_url(&app_handle, url.as_str());
+                }
+            });
+
+            // Register the app-wide notification action listener once, here,
+            // rather than per `notify_with_actions` call.
+            notifications::register_action_listener(&app.handle());
+
+            // Desktop-only system tray so the app can keep running in the
+            // background after the window is closed.
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            tray::setup(app)?;
+
+            // Mobile-only push notification registration; app-resume is
+            // handled separately in the run loop below.
+            #[cfg(any(target_os = "android", target_os = "ios"))]
+            mobile::setup(app)?;
+
+            // Spawn (and supervise) the bundled ESI proxy sidecar so request
+            // throttling and on-disk caching live in one place.
+            esi_proxy::spawn(&app.handle());
+
+            // Check for an update before the window is shown: a mandatory
+            // update is installed (and the app restarted) while the window
+            // stays hidden, so the outdated build is never actually used.
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.hide();
+                let app_handle = app.handle().clone();
+                let window_for_check = window.clone();
+                tauri::async_runtime::spawn(async move {
+                    updater::check_on_startup(&app_handle, window_for_check).await;
+                });
+            }
+
             // Log startup
             println!("EVE Sentinel starting...");
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .invoke_handler(tauri::generate_handler![
+            oauth::begin_oauth,
+            notifications::schedule_notification,
+            notifications::cancel_notification,
+            notifications::notify_with_actions,
+            tray::set_tray_tooltip,
+            tray::set_tray_icon_alert,
+            esi_proxy::esi_proxy_port,
+            updater::check_for_update,
+            updater::download_and_install_update,
+            mobile::register_push_token,
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                esi_proxy::kill(app_handle);
+            }
+
+            #[cfg(any(target_os = "android", target_os = "ios"))]
+            mobile::handle_run_event(app_handle, &event);
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            let _ = (app_handle, &event);
+        });
 }
@@ -0,0 +1,34 @@
+// Mobile (Android/iOS) setup: app-resume handling.
+// Mirrors the desktop-only `cfg` split in `main.rs`, just for the mobile targets.
+
+use tauri::{App, AppHandle, Emitter, RunEvent};
+
+/// Nothing mobile-specific needs registering at startup yet; see
+/// `register_push_token` for why push notifications aren't wired up.
+pub fn setup(_app: &App) -> tauri::Result<()> {
+    Ok(())
+}
+
+/// Refreshes app state after the OS resumes the webview from the
+/// background; wired into the main run loop's [`RunEvent::Resumed`].
+pub fn on_resume(app: &AppHandle) {
+    let _ = app.emit_to("main", "app://resumed", ());
+}
+
+pub fn handle_run_event(app: &AppHandle, event: &RunEvent) {
+    if let RunEvent::Resumed = event {
+        on_resume(app);
+    }
+}
+
+/// Push notifications are not implemented: `tauri_plugin_notification` only
+/// covers local/scheduled alerts and has no FCM/APNs registration or token
+/// API, mobile or otherwise. Delivering gatecamp/alert pushes needs a real
+/// native plugin (a dedicated push crate, or a custom Kotlin/Swift plugin
+/// invoked through `tauri::plugin::mobile`), which doesn't exist in this
+/// tree yet. Until then, this command reports the gap instead of pretending
+/// to return a token.
+#[tauri::command]
+pub fn register_push_token() -> Result<String, String> {
+    Err("push notifications are not implemented on mobile yet".to_string())
+}
@@ -0,0 +1,126 @@
+// Native OS notification commands.
+// Wraps `tauri_plugin_notification` with scheduling, cancellation, and actionable alerts.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::{Action, ActionType, NotificationExt};
+
+/// Tracks scheduled notifications by tag so they can be cancelled before
+/// they fire.
+#[derive(Default)]
+pub struct ScheduledNotifications(pub Mutex<HashMap<String, tauri::async_runtime::JoinHandle<()>>>);
+
+#[derive(Clone, serde::Serialize)]
+struct NotificationActionPayload {
+    tag: String,
+    action: String,
+}
+
+/// Schedules a notification to fire at `fire_at_unix_ms`, even if the window
+/// is unfocused or minimized to the tray. Re-scheduling the same `tag`
+/// cancels the previous timer.
+#[tauri::command]
+pub fn schedule_notification(
+    app: AppHandle,
+    title: String,
+    body: String,
+    fire_at_unix_ms: i64,
+    tag: String,
+) {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+    let delay = Duration::from_millis(fire_at_unix_ms.saturating_sub(now_ms).max(0) as u64);
+
+    let state = app.state::<ScheduledNotifications>();
+    if let Some(previous) = state.0.lock().unwrap().remove(&tag) {
+        previous.abort();
+    }
+
+    let app_handle = app.clone();
+    let fire_tag = tag.clone();
+    let handle = tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        let _ = app_handle
+            .notification()
+            .builder()
+            .title(title)
+            .body(body)
+            .show();
+        app_handle.state::<ScheduledNotifications>().0.lock().unwrap().remove(&fire_tag);
+    });
+
+    state.0.lock().unwrap().insert(tag, handle);
+}
+
+/// Cancels a previously scheduled notification by tag, if it hasn't fired
+/// yet.
+#[tauri::command]
+pub fn cancel_notification(app: AppHandle, tag: String) {
+    if let Some(handle) = app.state::<ScheduledNotifications>().0.lock().unwrap().remove(&tag) {
+        handle.abort();
+    }
+}
+
+/// Registers the single app-wide listener that forwards notification action
+/// clicks as `notification://action` to the main webview. Called once from
+/// `main.rs`'s `setup` - registering it per-notification (as
+/// `notify_with_actions` used to) would leave one stale listener per call,
+/// each re-emitting every future click.
+pub fn register_action_listener(app: &AppHandle) {
+    let app_handle = app.clone();
+    app.notification().on_action(move |action_id| {
+        let (tag, action) = action_id
+            .split_once("::")
+            .unwrap_or((action_id.as_str(), action_id.as_str()));
+        let _ = app_handle.emit_to(
+            "main",
+            "notification://action",
+            NotificationActionPayload {
+                tag: tag.to_string(),
+                action: action.to_string(),
+            },
+        );
+    });
+}
+
+/// Shows an immediate notification with one button per entry in `actions`,
+/// emitting `notification://action` (via [`register_action_listener`]) with
+/// the chosen action when the user clicks one.
+#[tauri::command]
+pub fn notify_with_actions(app: AppHandle, title: String, body: String, actions: Vec<String>, tag: String) {
+    let action_items: Vec<Action> = actions
+        .into_iter()
+        .map(|action| Action {
+            id: format!("{tag}::{action}"),
+            title: action,
+        })
+        .collect();
+
+    if let Err(err) = app
+        .notification()
+        .register_action_types(vec![ActionType {
+            id: tag.clone(),
+            actions: action_items,
+        }])
+    {
+        eprintln!("failed to register notification actions: {err}");
+        return;
+    }
+
+    let result = app
+        .notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .action_type_id(&tag)
+        .show();
+
+    if let Err(err) = result {
+        eprintln!("failed to show actionable notification: {err}");
+    }
+}
@@ -0,0 +1,77 @@
+// ESI OAuth deep-link callback handling.
+// Verifies the `eve-sentinel://auth/callback` redirect and forwards the code to the main webview.
+
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Emitter, Manager};
+use url::Url;
+
+/// Holds the CSRF `state` value the frontend generated before opening the
+/// browser, so the callback can be verified before we trust it.
+#[derive(Default)]
+pub struct PendingAuth(pub Mutex<Option<String>>);
+
+#[derive(Clone, serde::Serialize)]
+struct CodeReceivedPayload {
+    code: String,
+    state: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+struct AuthErrorPayload {
+    message: String,
+}
+
+/// Called by the frontend immediately before it opens the system browser, so
+/// we know which `state` value to expect on the callback.
+#[tauri::command]
+pub fn begin_oauth(app: AppHandle, state: String) {
+    let pending = app.state::<PendingAuth>();
+    *pending.0.lock().unwrap() = Some(state);
+}
+
+/// Parses an incoming `eve-sentinel://auth/callback` URL, verifies the CSRF
+/// `state` against what the frontend registered via [`begin_oauth`], and
+/// forwards the authorization code to the main webview.
+pub fn handle_callback_url(app: &AppHandle, url: &str) {
+    let Ok(parsed) = Url::parse(url) else {
+        return;
+    };
+    if parsed.scheme() != "eve-sentinel" {
+        return;
+    }
+
+    let mut code = None;
+    let mut state = None;
+    for (key, value) in parsed.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "state" => state = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    let (Some(code), Some(state)) = (code, state) else {
+        return;
+    };
+
+    let pending = app.state::<PendingAuth>();
+    let expected = pending.0.lock().unwrap().take();
+
+    if expected.as_deref() != Some(state.as_str()) {
+        let _ = app.emit_to(
+            "main",
+            "oauth://error",
+            AuthErrorPayload {
+                message: "OAuth state mismatch, possible CSRF attempt".into(),
+            },
+        );
+        return;
+    }
+
+    let _ = app.emit_to("main", "oauth://code-received", CodeReceivedPayload { code, state });
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.set_focus();
+    }
+}
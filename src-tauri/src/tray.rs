@@ -0,0 +1,83 @@
+// System tray icon for minimize-to-tray background operation.
+// Desktop-only; built in `setup` alongside the single-instance gate in `main.rs`.
+
+use tauri::image::Image;
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{App, AppHandle, Manager};
+
+const ICON_DEFAULT: &[u8] = include_bytes!("../icons/icon.png");
+const ICON_ALERT: &[u8] = include_bytes!("../icons/icon-alert.png");
+
+/// Builds the tray icon and context menu, and intercepts the main window's
+/// close event so it hides instead of exiting the app.
+pub fn setup(app: &App) -> tauri::Result<()> {
+    let show_hide = MenuItem::with_id(app, "show_hide", "Show/Hide", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show_hide, &quit])?;
+
+    TrayIconBuilder::with_id("main")
+        .menu(&menu)
+        .tooltip("EVE Sentinel")
+        .icon(Image::from_bytes(ICON_DEFAULT)?)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show_hide" => toggle_main_window(app),
+            "quit" => {
+                crate::esi_proxy::kill(app);
+                app.exit(0);
+            }
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                toggle_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    if let Some(window) = app.get_webview_window("main") {
+        let window_clone = window.clone();
+        window.on_window_event(move |event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                api.prevent_close();
+                let _ = window_clone.hide();
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        if window.is_visible().unwrap_or(false) {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
+/// Updates the tray icon's hover tooltip, e.g. to summarise unread alerts.
+#[tauri::command]
+pub fn set_tray_tooltip(app: AppHandle, text: String) {
+    if let Some(tray) = app.tray_by_id("main") {
+        let _ = tray.set_tooltip(Some(text.as_str()));
+    }
+}
+
+/// Swaps the tray icon between its default state and an alert state, e.g.
+/// when a hostile is reported in a watched system.
+#[tauri::command]
+pub fn set_tray_icon_alert(app: AppHandle, active: bool) {
+    let bytes = if active { ICON_ALERT } else { ICON_DEFAULT };
+    if let (Some(tray), Ok(image)) = (app.tray_by_id("main"), Image::from_bytes(bytes)) {
+        let _ = tray.set_icon(Some(image));
+    }
+}
@@ -0,0 +1,128 @@
+// Auto-update subsystem.
+// Wires `tauri_plugin_updater`'s signed-release checks into a startup check and invoke commands.
+
+use tauri::{AppHandle, Emitter, WebviewWindow};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+/// Fallback minimum version, used only if the update server's response
+/// doesn't advertise one. The real minimum comes from the server (see
+/// [`minimum_version`]) so a mandatory upgrade can be pushed without first
+/// getting every client onto a new build that raises a baked-in constant.
+const FALLBACK_MINIMUM_VERSION: &str = "0.1.0";
+
+#[derive(Clone, serde::Serialize)]
+struct UpdateProgressPayload {
+    downloaded: usize,
+    total: Option<u64>,
+}
+
+/// Runs before the main window is shown: checks for an update and, if it's
+/// mandatory (current version below the server-advertised minimum),
+/// downloads and installs it so the outdated build is never actually used.
+/// Otherwise reveals the window and leaves any optional update for the
+/// frontend to offer via `check_for_update` / `download_and_install_update`.
+pub async fn check_on_startup(app: &AppHandle, window: WebviewWindow) {
+    match app.updater() {
+        Ok(updater) => match updater.check().await {
+            Ok(Some(update)) => {
+                let minimum = minimum_version(&update);
+                if version_below(&update.current_version, &minimum) {
+                    if let Err(err) = install(app, update).await {
+                        // `install` only returns on failure (it restarts the
+                        // process on success), so fall through and show the
+                        // window rather than blocking launch forever.
+                        eprintln!("mandatory update failed: {err}");
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(err) => eprintln!("update check failed: {err}"),
+        },
+        Err(err) => eprintln!("updater unavailable: {err}"),
+    }
+
+    let _ = window.show();
+}
+
+/// Reads the server-advertised minimum version out of the update manifest's
+/// raw JSON (not one of the plugin's built-in fields), falling back to
+/// [`FALLBACK_MINIMUM_VERSION`] if the server didn't send one.
+fn minimum_version(update: &Update) -> String {
+    update
+        .raw_json
+        .get("minimum_version")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .unwrap_or_else(|| FALLBACK_MINIMUM_VERSION.to_string())
+}
+
+fn version_below(current_version: &str, minimum_version: &str) -> bool {
+    let minimum = semver::Version::parse(minimum_version)
+        .expect("minimum_version must be valid semver");
+    match semver::Version::parse(current_version) {
+        Ok(current) => current < minimum,
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_digit_segments_compare_numerically_not_lexically() {
+        assert!(!version_below("0.10.0", "0.9.0"));
+        assert!(version_below("0.2.0", "0.10.0"));
+    }
+
+    #[test]
+    fn unparseable_version_is_treated_as_mandatory() {
+        assert!(version_below("not-a-version", "0.1.0"));
+    }
+}
+
+/// Checks for an available update and reports its version, if any, so the
+/// frontend can prompt the user before downloading.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<String>, String> {
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    let update = updater.check().await.map_err(|e| e.to_string())?;
+    Ok(update.map(|u| u.version))
+}
+
+/// Downloads and installs the pending update, emitting `update://progress`
+/// events as bytes arrive, then restarts the app.
+#[tauri::command]
+pub async fn download_and_install_update(app: AppHandle) -> Result<(), String> {
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "no update available".to_string())?;
+
+    install(&app, update).await.map_err(|e| e.to_string())
+}
+
+async fn install(app: &AppHandle, update: Update) -> tauri::Result<()> {
+    let app_handle = app.clone();
+    let mut downloaded = 0usize;
+    update
+        .download_and_install(
+            move |chunk_len, total| {
+                downloaded += chunk_len;
+                let _ = app_handle.emit_to(
+                    "main",
+                    "update://progress",
+                    UpdateProgressPayload {
+                        downloaded,
+                        total,
+                    },
+                );
+            },
+            || {},
+        )
+        .await?;
+
+    app.restart();
+}